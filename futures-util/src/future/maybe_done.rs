@@ -79,6 +79,37 @@ impl<Fut: Future> MaybeDone<Fut> {
             }
         }
     }
+
+    /// Returns `true` if this `MaybeDone` is in the [`Gone`](MaybeDone::Gone)
+    /// state, i.e. its output has already been taken via
+    /// [`take_output`](MaybeDone::take_output).
+    #[inline]
+    pub fn is_gone(&self) -> bool {
+        match self {
+            MaybeDone::Gone => true,
+            MaybeDone::Future(_) | MaybeDone::Done(_) => false,
+        }
+    }
+
+    /// Returns `true` if the inner future has completed and its output
+    /// has not yet been taken.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        match self {
+            MaybeDone::Done(_) => true,
+            MaybeDone::Future(_) | MaybeDone::Gone => false,
+        }
+    }
+
+    /// Drops any state currently held and replaces it with
+    /// [`MaybeDone::Future`] holding the given future, which the next poll
+    /// drives. This allows a pinned `MaybeDone` to be reused across
+    /// multiple rounds instead of being reallocated. The future is not
+    /// polled by this method.
+    #[inline]
+    pub fn set_future(self: Pin<&mut Self>, fut: Fut) {
+        Pin::set(self, MaybeDone::Future(fut));
+    }
 }
 
 impl<Fut: Future> FusedFuture for MaybeDone<Fut> {
@@ -0,0 +1,12 @@
+//! Asynchronous values.
+
+mod maybe_done;
+pub use self::maybe_done::{maybe_done, MaybeDone};
+
+mod try_maybe_done;
+pub use self::try_maybe_done::{try_maybe_done, TryMaybeDone};
+
+mod poll_fn;
+pub use self::poll_fn::{poll_fn, PollFn};
+
+pub use futures_core::future::FusedFuture;
@@ -0,0 +1,104 @@
+//! Definition of the TryMaybeDone combinator
+
+use core::marker::Unpin;
+use core::mem;
+use core::pin::Pin;
+use futures_core::future::{FusedFuture, Future, TryFuture};
+use futures_core::task::{LocalWaker, Poll};
+
+/// A future that may have completed with an error.
+///
+/// This is created by the [`try_maybe_done()`] function.
+#[derive(Debug)]
+pub enum TryMaybeDone<Fut: TryFuture> {
+    /// A not-yet-completed future
+    Future(Fut),
+    /// The success value of the completed future
+    Done(Fut::Ok),
+    /// The empty variant after the result of a [`TryMaybeDone`] has been
+    /// taken using the [`take_output`](TryMaybeDone::take_output) method,
+    /// or if the future produced an error.
+    Gone,
+}
+
+// Safe because we never generate `Pin<&mut Fut::Ok>`
+impl<Fut: TryFuture + Unpin> Unpin for TryMaybeDone<Fut> {}
+
+/// Wraps a future into a `TryMaybeDone`
+pub fn try_maybe_done<Fut: TryFuture>(future: Fut) -> TryMaybeDone<Fut> {
+    TryMaybeDone::Future(future)
+}
+
+impl<Fut: TryFuture> TryMaybeDone<Fut> {
+    /// Returns an [`Option`] containing a mutable reference to the output of the future.
+    /// The output of this method will be [`Some`] if and only if the inner
+    /// future has completed successfully and [`take_output`](TryMaybeDone::take_output)
+    /// has not yet been called.
+    #[inline]
+    #[allow(clippy::needless_lifetimes)] // https://github.com/rust-lang/rust/issues/52675
+    pub fn output_mut<'a>(self: Pin<&'a mut Self>) -> Option<&'a mut Fut::Ok> {
+        unsafe {
+            let this = Pin::get_mut_unchecked(self);
+            match this {
+                TryMaybeDone::Done(res) => Some(res),
+                _ => None,
+            }
+        }
+    }
+
+    /// Attempt to take the output of a `TryMaybeDone` without driving it
+    /// towards completion.
+    #[inline]
+    pub fn take_output(self: Pin<&mut Self>) -> Option<Fut::Ok> {
+        unsafe {
+            let this = Pin::get_mut_unchecked(self);
+            match this {
+                TryMaybeDone::Done(_) => {},
+                TryMaybeDone::Future(_) | TryMaybeDone::Gone => return None,
+            };
+            if let TryMaybeDone::Done(output) = mem::replace(this, TryMaybeDone::Gone) {
+                Some(output)
+            } else {
+                unreachable!()
+            }
+        }
+    }
+}
+
+impl<Fut: TryFuture> FusedFuture for TryMaybeDone<Fut> {
+    fn is_terminated(&self) -> bool {
+        match self {
+            TryMaybeDone::Future(_) => false,
+            TryMaybeDone::Done(_) | TryMaybeDone::Gone => true,
+        }
+    }
+}
+
+impl<Fut: TryFuture> Future for TryMaybeDone<Fut> {
+    type Output = Result<(), Fut::Error>;
+
+    fn poll(mut self: Pin<&mut Self>, lw: &LocalWaker) -> Poll<Self::Output> {
+        let res = unsafe {
+            match Pin::get_mut_unchecked(self.as_mut()) {
+                TryMaybeDone::Future(f) => {
+                    match Pin::new_unchecked(f).try_poll(lw) {
+                        Poll::Ready(res) => res,
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+                TryMaybeDone::Done(_) => return Poll::Ready(Ok(())),
+                TryMaybeDone::Gone => panic!("TryMaybeDone polled after value taken"),
+            }
+        };
+        match res {
+            Ok(res) => {
+                Pin::set(self, TryMaybeDone::Done(res));
+                Poll::Ready(Ok(()))
+            }
+            Err(e) => {
+                Pin::set(self, TryMaybeDone::Gone);
+                Poll::Ready(Err(e))
+            }
+        }
+    }
+}
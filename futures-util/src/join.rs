@@ -0,0 +1,127 @@
+//! The `join` and `try_join` macros.
+
+/// Polls multiple futures simultaneously, returning a tuple
+/// of all results once complete.
+///
+/// While `join!(a, b)` is similar to `(await!(a), await!(b))`, `join!`
+/// polls the futures concurrently rather than driving them one at a
+/// time to completion, so it is the combinator of choice when several
+/// independent futures need to make progress together.
+///
+/// This macro is only usable inside of `async` functions, closures, and
+/// blocks.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::join;
+///
+/// let a = future::ready(1);
+/// let b = future::ready(2);
+///
+/// assert_eq!(join!(a, b), (1, 2));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($($fut:ident),* $(,)?) => { {
+        $(
+            // Move the future into a local so that it can be pinned
+            // without requiring the caller to import any extra traits.
+            let mut $fut = $crate::future::maybe_done($fut);
+        )*
+        $(
+            let mut $fut = unsafe { $crate::core_reexport::pin::Pin::new_unchecked(&mut $fut) };
+        )*
+
+        $crate::r#await!($crate::future::poll_fn(move |lw| {
+            use $crate::future::FusedFuture;
+            use $crate::core_reexport::future::Future;
+            use $crate::core_reexport::task::Poll;
+
+            let mut all_done = true;
+            $(
+                if !$fut.is_terminated() {
+                    if Future::poll($fut.as_mut(), lw).is_pending() {
+                        all_done = false;
+                    }
+                }
+            )*
+            if all_done {
+                Poll::Ready(($(
+                    $fut.as_mut().take_output().unwrap(),
+                )*))
+            } else {
+                Poll::Pending
+            }
+        }))
+    } }
+}
+
+/// Polls multiple futures simultaneously, resolving to a [`Result`]
+/// containing either a tuple of all the successful outputs or the first
+/// error encountered.
+///
+/// `try_join!` is built on [`TryMaybeDone`](crate::future::TryMaybeDone)
+/// rather than `MaybeDone`, so as soon as any one of the futures resolves
+/// to an `Err` the whole group is abandoned and `try_join!` returns that
+/// error immediately, without waiting for the remaining futures.
+///
+/// This macro is only usable inside of `async` functions, closures, and
+/// blocks.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(async_await, await_macro, futures_api)]
+/// # futures::executor::block_on(async {
+/// use futures::future;
+/// use futures::try_join;
+///
+/// let a = future::ready(Ok::<i32, i32>(1));
+/// let b = future::ready(Ok::<i32, i32>(2));
+/// assert_eq!(try_join!(a, b), Ok((1, 2)));
+///
+/// let c = future::ready(Ok::<i32, i32>(1));
+/// let d = future::ready(Err::<i32, i32>(2));
+/// assert_eq!(try_join!(c, d), Err(2));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! try_join {
+    ($($fut:ident),* $(,)?) => { {
+        $(
+            let mut $fut = $crate::future::try_maybe_done($fut);
+        )*
+        $(
+            let mut $fut = unsafe { $crate::core_reexport::pin::Pin::new_unchecked(&mut $fut) };
+        )*
+
+        $crate::r#await!($crate::future::poll_fn(move |lw| {
+            use $crate::future::FusedFuture;
+            use $crate::core_reexport::future::Future;
+            use $crate::core_reexport::task::Poll;
+
+            let mut all_done = true;
+            $(
+                if !$fut.is_terminated() {
+                    match Future::poll($fut.as_mut(), lw) {
+                        Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                        Poll::Ready(Ok(())) => {}
+                        Poll::Pending => all_done = false,
+                    }
+                }
+            )*
+            if all_done {
+                Poll::Ready(Ok(($(
+                    $fut.as_mut().take_output().unwrap(),
+                )*)))
+            } else {
+                Poll::Pending
+            }
+        }))
+    } }
+}
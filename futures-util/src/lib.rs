@@ -0,0 +1,13 @@
+//! Combinators and utilities for working with `Future`s, `Stream`s, and
+//! `Sink`s.
+
+// Not public API, used by the `join!`/`try_join!` macros so that callers
+// do not need `core` or the `await!` macro in scope at the call site.
+#[doc(hidden)]
+pub use core as core_reexport;
+#[doc(hidden)]
+pub use core::r#await;
+
+pub mod future;
+
+mod join;